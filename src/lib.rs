@@ -17,8 +17,12 @@
 
 use core::convert::TryFrom;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Represents the human-friendly interpretation of the AQI
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AirQualityLevel {
     /// The air quality is good and safe for everyone
     Good,
@@ -35,6 +39,34 @@ pub enum AirQualityLevel {
     Hazardous,
 }
 
+impl AirQualityLevel {
+    /// Returns the canonical display label for this category, e.g. "Unhealthy for Sensitive
+    /// Groups", as used by EPA/AirNow
+    pub fn label(&self) -> &'static str {
+        match self {
+            AirQualityLevel::Good => "Good",
+            AirQualityLevel::Moderate => "Moderate",
+            AirQualityLevel::UnhealthySensitive => "Unhealthy for Sensitive Groups",
+            AirQualityLevel::Unhealthy => "Unhealthy",
+            AirQualityLevel::VeryUnhealthy => "Very Unhealthy",
+            AirQualityLevel::Hazardous => "Hazardous",
+        }
+    }
+
+    /// Returns the standard AQI color for this category, as a `#rrggbb` hex string, as used by
+    /// EPA/AirNow
+    pub fn color_hex(&self) -> &'static str {
+        match self {
+            AirQualityLevel::Good => "#00e400",
+            AirQualityLevel::Moderate => "#ffff00",
+            AirQualityLevel::UnhealthySensitive => "#ff7e00",
+            AirQualityLevel::Unhealthy => "#ff0000",
+            AirQualityLevel::VeryUnhealthy => "#8f3f97",
+            AirQualityLevel::Hazardous => "#7e0023",
+        }
+    }
+}
+
 macro_rules! def_try_from_aq {
     ($tpe:ty) => {
         impl TryFrom<$tpe> for AirQualityLevel {
@@ -64,6 +96,7 @@ def_try_from_aq!(i64);
 
 /// Result type for AQI calculations
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AirQuality {
     /// The numerical AQI value, in a range between 0 and 500
     pub aqi: u32,
@@ -71,386 +104,463 @@ pub struct AirQuality {
     pub level: AirQualityLevel,
 }
 
-struct Breakpoint {
-    conc_low: f64,
-    conc_high: f64,
-    aqi_low: u32,
-    aqi_high: u32,
-    level: AirQualityLevel,
+/// A category describing one range of a [`BreakpointTable`].
+///
+/// The built-in US EPA tables use the six standard [`AirQualityLevel`]
+/// categories. A custom table (see [`calc_aqi`]) may instead use a
+/// [`Category::Named`] label, since not every national or regional scheme
+/// maps onto the US six-bucket scale (e.g. Finland's "Satisfactory" or
+/// "Fair", Taiwan's category names).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Category {
+    /// One of the six standard US EPA categories
+    Us(AirQualityLevel),
+    /// A category name from a custom, typically non-US, breakpoint table
+    Named(&'static str),
+}
+
+/// A single row of a [`BreakpointTable`]: the concentration and AQI range
+/// for one category.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Breakpoint {
+    /// The low end of the concentration range this breakpoint covers
+    pub conc_low: f64,
+    /// The high end of the concentration range this breakpoint covers
+    pub conc_high: f64,
+    /// The low end of the AQI range this breakpoint covers
+    pub aqi_low: u32,
+    /// The high end of the AQI range this breakpoint covers
+    pub aqi_high: u32,
+    /// The category this breakpoint falls into
+    pub category: Category,
 }
 
-const OZONE8_BREAKPOINTS: [Breakpoint; 5] = [
+/// A table of [`Breakpoint`]s defining an AQI scale for a single pollutant.
+///
+/// This crate's own US EPA tables (e.g. [`PM25_BREAKPOINTS`]) are plain
+/// values of this type, and a caller may supply their own to [`calc_aqi`]
+/// to compute a non-US index, such as one reporting µg/m³ gas
+/// concentrations directly or using a different AQI ceiling.
+pub type BreakpointTable = [Breakpoint];
+
+/// US EPA breakpoints for the 8-hour ozone AQI, in ppm
+pub const OZONE8_BREAKPOINTS: [Breakpoint; 5] = [
     Breakpoint {
         conc_low: 0.000,
         conc_high: 0.054,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 0.055,
         conc_high: 0.070,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 0.071,
         conc_high: 0.085,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 0.086,
         conc_high: 0.105,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 0.106,
         conc_high: 0.200,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
 ];
-const OZONE1_BREAKPOINTS: [Breakpoint; 5] = [
+/// US EPA breakpoints for the 1-hour ozone AQI, in ppm
+pub const OZONE1_BREAKPOINTS: [Breakpoint; 5] = [
     Breakpoint {
         conc_low: 0.125,
         conc_high: 0.164,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 0.165,
         conc_high: 0.204,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 0.205,
         conc_high: 0.404,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
     Breakpoint {
         conc_low: 0.405,
         conc_high: 0.504,
         aqi_low: 301,
         aqi_high: 400,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
     Breakpoint {
         conc_low: 0.505,
         conc_high: 0.604,
         aqi_low: 401,
         aqi_high: 500,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
 ];
-const PM25_BREAKPOINTS: [Breakpoint; 7] = [
+/// US EPA breakpoints for the 24-hour PM2.5 AQI, in µg/m³
+pub const PM25_BREAKPOINTS: [Breakpoint; 7] = [
     Breakpoint {
         conc_low: 0.0,
         conc_high: 12.0,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 12.1,
         conc_high: 35.4,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 35.5,
         conc_high: 55.4,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 55.5,
         conc_high: 150.4,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 150.5,
         conc_high: 250.4,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
     Breakpoint {
         conc_low: 250.5,
         conc_high: 350.4,
         aqi_low: 301,
         aqi_high: 400,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
     Breakpoint {
         conc_low: 350.5,
         conc_high: 500.4,
         aqi_low: 401,
         aqi_high: 500,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
 ];
-const PM10_BREAKPOINTS: [Breakpoint; 7] = [
+/// US EPA breakpoints for the 24-hour PM10 AQI, in µg/m³
+pub const PM10_BREAKPOINTS: [Breakpoint; 7] = [
     Breakpoint {
         conc_low: 0.0,
         conc_high: 54.0,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 55.0,
         conc_high: 154.0,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 155.0,
         conc_high: 254.0,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 255.0,
         conc_high: 354.0,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 355.0,
         conc_high: 424.0,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
     Breakpoint {
         conc_low: 425.0,
         conc_high: 504.0,
         aqi_low: 301,
         aqi_high: 400,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
     Breakpoint {
         conc_low: 505.0,
         conc_high: 604.0,
         aqi_low: 401,
         aqi_high: 500,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
 ];
-const CO_BREAKPOINTS: [Breakpoint; 7] = [
+/// US EPA breakpoints for the 8-hour CO AQI, in ppm
+pub const CO_BREAKPOINTS: [Breakpoint; 7] = [
     Breakpoint {
         conc_low: 0.0,
         conc_high: 4.4,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 4.5,
         conc_high: 9.4,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 9.5,
         conc_high: 12.4,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 12.5,
         conc_high: 15.4,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 15.5,
         conc_high: 30.4,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
     Breakpoint {
         conc_low: 30.5,
         conc_high: 40.4,
         aqi_low: 301,
         aqi_high: 400,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
     Breakpoint {
         conc_low: 40.5,
         conc_high: 50.4,
         aqi_low: 401,
         aqi_high: 500,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
 ];
-const SO2_1_BREAKPOINTS: [Breakpoint; 3] = [
+/// US EPA breakpoints for the 1-hour SO₂ AQI, in ppb
+pub const SO2_1_BREAKPOINTS: [Breakpoint; 3] = [
     Breakpoint {
         conc_low: 0.0,
         conc_high: 35.0,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 36.0,
         conc_high: 75.0,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 76.0,
         conc_high: 185.0,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
 ];
-const SO2_24_BREAKPOINTS: [Breakpoint; 7] = [
+/// US EPA breakpoints for the 24-hour SO₂ AQI, in ppb
+pub const SO2_24_BREAKPOINTS: [Breakpoint; 7] = [
     Breakpoint {
         conc_low: 0.0,
         conc_high: 35.0,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 36.0,
         conc_high: 75.0,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 76.0,
         conc_high: 185.0,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 186.0,
         conc_high: 304.0,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 305.0,
         conc_high: 604.0,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
     Breakpoint {
         conc_low: 605.0,
         conc_high: 804.0,
         aqi_low: 301,
         aqi_high: 400,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
     Breakpoint {
         conc_low: 805.0,
         conc_high: 1004.0,
         aqi_low: 401,
         aqi_high: 500,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
 ];
-const NO2_BREAKPOINTS: [Breakpoint; 7] = [
+/// US EPA breakpoints for the 1-hour NO₂ AQI, in ppb
+pub const NO2_BREAKPOINTS: [Breakpoint; 7] = [
     Breakpoint {
         conc_low: 0.0,
         conc_high: 53.0,
         aqi_low: 0,
         aqi_high: 50,
-        level: AirQualityLevel::Good,
+        category: Category::Us(AirQualityLevel::Good),
     },
     Breakpoint {
         conc_low: 54.0,
         conc_high: 100.0,
         aqi_low: 51,
         aqi_high: 100,
-        level: AirQualityLevel::Moderate,
+        category: Category::Us(AirQualityLevel::Moderate),
     },
     Breakpoint {
         conc_low: 101.0,
         conc_high: 360.0,
         aqi_low: 101,
         aqi_high: 150,
-        level: AirQualityLevel::UnhealthySensitive,
+        category: Category::Us(AirQualityLevel::UnhealthySensitive),
     },
     Breakpoint {
         conc_low: 361.0,
         conc_high: 649.0,
         aqi_low: 151,
         aqi_high: 200,
-        level: AirQualityLevel::Unhealthy,
+        category: Category::Us(AirQualityLevel::Unhealthy),
     },
     Breakpoint {
         conc_low: 650.0,
         conc_high: 1249.0,
         aqi_low: 201,
         aqi_high: 300,
-        level: AirQualityLevel::VeryUnhealthy,
+        category: Category::Us(AirQualityLevel::VeryUnhealthy),
     },
     Breakpoint {
         conc_low: 1250.0,
         conc_high: 1649.0,
         aqi_low: 301,
         aqi_high: 400,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
     Breakpoint {
         conc_low: 1650.0,
         conc_high: 2049.0,
         aqi_low: 401,
         aqi_high: 500,
-        level: AirQualityLevel::Hazardous,
+        category: Category::Us(AirQualityLevel::Hazardous),
     },
 ];
 
-fn find_breakpoint(breakpoints: &[Breakpoint], concentration: f64) -> Option<&Breakpoint> {
+fn find_breakpoint(breakpoints: &BreakpointTable, concentration: f64) -> Option<&Breakpoint> {
     breakpoints.iter().find(|breakpoint| {
         breakpoint.conc_low <= concentration && concentration <= breakpoint.conc_high
     })
 }
 
-fn calc_aqi(breakpoints: &[Breakpoint], concentration: f64) -> Option<AirQuality> {
+/// The result of evaluating a [`BreakpointTable`] against a concentration:
+/// the calculated AQI value and the category of the breakpoint it fell
+/// into.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CategorizedAqi {
+    /// The numerical AQI value
+    pub aqi: u32,
+    /// The category the concentration falls into
+    pub category: Category,
+}
+
+/// Calculates an AQI value and category from a breakpoint table and a
+/// concentration.
+///
+/// This is the general machinery behind all of this crate's per-pollutant
+/// functions (e.g. [`pm2_5`], [`ozone8`]), exposed so callers can plug in
+/// their own national or regional [`BreakpointTable`] instead of the US EPA
+/// ones built into this crate — for example a scheme that reports µg/m³ gas
+/// concentrations directly, uses a different AQI ceiling, or has its own
+/// named categories rather than the US six-bucket scale. Concentrations
+/// outside of every breakpoint's range return `None`.
+///
+/// # Arguments
+///
+/// * `breakpoints` - The breakpoint table to evaluate against
+/// * `concentration` - The pollutant concentration to look up
+pub fn calc_aqi(breakpoints: &BreakpointTable, concentration: f64) -> Option<CategorizedAqi> {
     find_breakpoint(breakpoints, concentration).map(|breakpoint| {
         let aqi = ((breakpoint.aqi_high as f64 - breakpoint.aqi_low as f64)
             / (breakpoint.conc_high - breakpoint.conc_low))
             * (concentration - breakpoint.conc_low)
             + (breakpoint.aqi_low as f64);
-        AirQuality {
+        CategorizedAqi {
             aqi: round(aqi),
-            level: breakpoint.level,
+            category: breakpoint.category,
         }
     })
 }
 
+fn calc_aqi_us(breakpoints: &BreakpointTable, concentration: f64) -> Option<AirQuality> {
+    calc_aqi(breakpoints, concentration).map(|result| AirQuality {
+        aqi: result.aqi,
+        level: match result.category {
+            Category::Us(level) => level,
+            Category::Named(name) => {
+                unreachable!("built-in US breakpoint tables only use Category::Us, got {}", name)
+            }
+        },
+    })
+}
+
 fn trunc(value: f64, nplaces: u32) -> f64 {
     let truncator = 10_u32.pow(nplaces) as f64;
     ((value * truncator) as u64) as f64 / truncator
@@ -466,7 +576,7 @@ fn trunc(value: f64, nplaces: u32) -> f64 {
 ///
 /// * `concentration` - The 8-hour ozone concentration in ppm
 pub fn ozone8(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&OZONE8_BREAKPOINTS, trunc(concentration, 3))
+    calc_aqi_us(&OZONE8_BREAKPOINTS, trunc(concentration, 3))
 }
 
 /// Calculates the ozone Air Quality Index from the provided 1-hour concentration
@@ -479,7 +589,7 @@ pub fn ozone8(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 1-hour ozone concentration in ppm
 pub fn ozone1(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&OZONE1_BREAKPOINTS, trunc(concentration, 3))
+    calc_aqi_us(&OZONE1_BREAKPOINTS, trunc(concentration, 3))
 }
 
 /// Calculates the PM2.5 Air Quality Index from the provided 24-hour concentration
@@ -490,7 +600,7 @@ pub fn ozone1(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 24-hour PM2.5 concentration in µg/m³
 pub fn pm2_5(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&PM25_BREAKPOINTS, trunc(concentration, 1))
+    calc_aqi_us(&PM25_BREAKPOINTS, trunc(concentration, 1))
 }
 
 /// Calcuates the EPA-adjusted PM2.5 Air Quality Index for the provided 24-hour concentration
@@ -509,7 +619,7 @@ pub fn pm2_5(concentration: f64) -> Option<AirQuality> {
 /// * `humidity` - Relative humidity % (between 0.0 - 1.0)
 pub fn pm2_5_epa(concentration: f64, humidity: f64) -> Option<AirQuality> {
     if (0.0..=1.0).contains(&humidity) {
-        calc_aqi(
+        calc_aqi_us(
             &PM25_BREAKPOINTS,
             trunc(0.52 * concentration - 0.085 * humidity + 5.71, 1),
         )
@@ -533,7 +643,7 @@ pub fn pm2_5_epa(concentration: f64, humidity: f64) -> Option<AirQuality> {
 /// * `concentration` - The 24-hour PM2.5 concentration in µg/m³
 pub fn pm2_5_lrapa(concentration: f64) -> Option<AirQuality> {
     if concentration <= 65.0 {
-        calc_aqi(&PM25_BREAKPOINTS, trunc(0.5 * concentration - 0.66, 1))
+        calc_aqi_us(&PM25_BREAKPOINTS, trunc(0.5 * concentration - 0.66, 1))
     } else {
         None
     }
@@ -552,7 +662,7 @@ pub fn pm2_5_lrapa(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 24-hour PM2.5 concentration in µg/m³
 pub fn pm2_5_aqandu(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&PM25_BREAKPOINTS, trunc(0.778 * concentration + 2.65, 1))
+    calc_aqi_us(&PM25_BREAKPOINTS, trunc(0.778 * concentration + 2.65, 1))
 }
 
 /// Calculates the PM10 Air Quality Index from the provided 24-hour concentration
@@ -563,7 +673,97 @@ pub fn pm2_5_aqandu(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 24-hour PM10 concentration in µg/m³
 pub fn pm10(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&PM10_BREAKPOINTS, concentration as u32 as f64)
+    calc_aqi_us(&PM10_BREAKPOINTS, concentration as u32 as f64)
+}
+
+/// The maximum number of hourly readings the NowCast calculation considers.
+/// Any readings beyond this (i.e. older than 12 hours) are ignored.
+const NOWCAST_MAX_HOURS: usize = 12;
+
+/// Computes the EPA NowCast concentration from up to 12 hours of readings.
+///
+/// `hourly` is ordered newest-first (`hourly[0]` is the current hour) and
+/// may contain gaps; missing hours are represented as `None` and simply
+/// contribute nothing to the result, though they still advance the
+/// exponent used to weight older hours. At least two of the three most
+/// recent hours must be present, or `None` is returned. Only the first 12
+/// elements are considered; any beyond that are ignored.
+///
+/// See
+/// [https://www3.epa.gov/airnow/aqicalctest/nowcastdocumentation.pdf](https://www3.epa.gov/airnow/aqicalctest/nowcastdocumentation.pdf)
+/// for more information.
+///
+/// # Arguments
+///
+/// * `hourly` - Hourly concentrations, newest-first; only the first 12 are used
+/// * `min_weight` - The floor applied to the `min/max` weight factor (0.5 for PM)
+fn nowcast_concentration(hourly: &[Option<f64>], min_weight: f64) -> Option<f64> {
+    let hourly = &hourly[..hourly.len().min(NOWCAST_MAX_HOURS)];
+
+    if hourly.iter().take(3).filter(|v| v.is_some()).count() < 2 {
+        return None;
+    }
+
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    for c in hourly.iter().flatten() {
+        min = min.min(*c);
+        max = max.max(*c);
+    }
+    if !min.is_finite() || !max.is_finite() {
+        return None;
+    }
+
+    let weight = if max == 0.0 {
+        1.0
+    } else {
+        (min / max).max(min_weight)
+    };
+
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    let mut w = 1.0;
+    for c in hourly.iter() {
+        if let Some(c) = c {
+            numerator += w * c;
+            denominator += w;
+        }
+        w *= weight;
+    }
+
+    if denominator == 0.0 {
+        None
+    } else {
+        Some(numerator / denominator)
+    }
+}
+
+/// Calculates the PM2.5 NowCast Air Quality Index from up to 12 hours of concentration readings
+///
+/// This gives a more responsive AQI than the 24-hour average in [`pm2_5`] for
+/// callers with hourly sensor data, by weighting recent hours more heavily
+/// when the concentration is trending.
+///
+/// # Arguments
+///
+/// * `hourly` - Hourly PM2.5 concentrations in µg/m³, newest-first; only the
+///   first 12 are used
+pub fn pm2_5_nowcast(hourly: &[Option<f64>]) -> Option<AirQuality> {
+    nowcast_concentration(hourly, 0.5).and_then(pm2_5)
+}
+
+/// Calculates the PM10 NowCast Air Quality Index from up to 12 hours of concentration readings
+///
+/// This gives a more responsive AQI than the 24-hour average in [`pm10`] for
+/// callers with hourly sensor data, by weighting recent hours more heavily
+/// when the concentration is trending.
+///
+/// # Arguments
+///
+/// * `hourly` - Hourly PM10 concentrations in µg/m³, newest-first; only the
+///   first 12 are used
+pub fn pm10_nowcast(hourly: &[Option<f64>]) -> Option<AirQuality> {
+    nowcast_concentration(hourly, 0.5).and_then(pm10)
 }
 
 /// Calculates the carbon monoxide Air Quality Index from the provided 8-hour concentration
@@ -574,7 +774,7 @@ pub fn pm10(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 8-hour CO concentration in ppm
 pub fn co(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&CO_BREAKPOINTS, trunc(concentration, 1))
+    calc_aqi_us(&CO_BREAKPOINTS, trunc(concentration, 1))
 }
 
 /// Calculates the sulfur dioxide Air Quality Index from the provided 1-hour concentration
@@ -587,7 +787,7 @@ pub fn co(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 1-hour SO₂ concentration in ppb
 pub fn so2_1(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&SO2_1_BREAKPOINTS, trunc(concentration, 0))
+    calc_aqi_us(&SO2_1_BREAKPOINTS, trunc(concentration, 0))
 }
 
 /// Calculates the sulfur dioxide Air Quality Index from the provided 24-hour concentration
@@ -598,7 +798,7 @@ pub fn so2_1(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 24-hour SO₂ concentration in ppb
 pub fn so2_24(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&SO2_24_BREAKPOINTS, trunc(concentration, 0))
+    calc_aqi_us(&SO2_24_BREAKPOINTS, trunc(concentration, 0))
 }
 
 /// Calculates the nitrogen dioxide Air Quality Index from the provided 1-hour concentration
@@ -609,7 +809,231 @@ pub fn so2_24(concentration: f64) -> Option<AirQuality> {
 ///
 /// * `concentration` - The 1-hour NO₂ concentration in ppb
 pub fn no2(concentration: f64) -> Option<AirQuality> {
-    calc_aqi(&NO2_BREAKPOINTS, trunc(concentration, 0))
+    calc_aqi_us(&NO2_BREAKPOINTS, trunc(concentration, 0))
+}
+
+/// Molar mass of ozone (O₃), in g/mol
+pub const MOLAR_MASS_O3: f64 = 48.00;
+/// Molar mass of nitrogen dioxide (NO₂), in g/mol
+pub const MOLAR_MASS_NO2: f64 = 46.01;
+/// Molar mass of sulfur dioxide (SO₂), in g/mol
+pub const MOLAR_MASS_SO2: f64 = 64.07;
+/// Molar mass of carbon monoxide (CO), in g/mol
+pub const MOLAR_MASS_CO: f64 = 28.01;
+
+/// Molar volume of an ideal gas at 25 °C and 1 atm, in L/mol
+///
+/// Used to convert between ppb/ppm and µg/m³ gas concentrations.
+const MOLAR_VOLUME_25C_1ATM: f64 = 24.45;
+
+/// Converts a gas concentration from parts per billion to µg/m³, at 25 °C and 1 atm
+///
+/// # Arguments
+///
+/// * `ppb` - The concentration in parts per billion
+/// * `molar_mass` - The pollutant's molar mass, in g/mol (see the `MOLAR_MASS_*` constants)
+pub fn ppb_to_ugm3(ppb: f64, molar_mass: f64) -> f64 {
+    ppb * (molar_mass / MOLAR_VOLUME_25C_1ATM)
+}
+
+/// Converts a gas concentration from µg/m³ to parts per billion, at 25 °C and 1 atm
+///
+/// # Arguments
+///
+/// * `ugm3` - The concentration in µg/m³
+/// * `molar_mass` - The pollutant's molar mass, in g/mol (see the `MOLAR_MASS_*` constants)
+pub fn ugm3_to_ppb(ugm3: f64, molar_mass: f64) -> f64 {
+    ugm3 * (MOLAR_VOLUME_25C_1ATM / molar_mass)
+}
+
+/// Converts a gas concentration from parts per million to µg/m³, at 25 °C and 1 atm
+///
+/// # Arguments
+///
+/// * `ppm` - The concentration in parts per million
+/// * `molar_mass` - The pollutant's molar mass, in g/mol (see the `MOLAR_MASS_*` constants)
+pub fn ppm_to_ugm3(ppm: f64, molar_mass: f64) -> f64 {
+    ppb_to_ugm3(ppm * 1000.0, molar_mass)
+}
+
+/// Converts a gas concentration from µg/m³ to parts per million, at 25 °C and 1 atm
+///
+/// # Arguments
+///
+/// * `ugm3` - The concentration in µg/m³
+/// * `molar_mass` - The pollutant's molar mass, in g/mol (see the `MOLAR_MASS_*` constants)
+pub fn ugm3_to_ppm(ugm3: f64, molar_mass: f64) -> f64 {
+    ugm3_to_ppb(ugm3, molar_mass) / 1000.0
+}
+
+/// Calculates the ozone Air Quality Index from an 8-hour concentration given in µg/m³
+///
+/// Converts from µg/m³ to ppm (this crate's native unit for [`ozone8`]) using
+/// ozone's molar mass, then computes the AQI as normal.
+///
+/// # Arguments
+///
+/// * `concentration` - The 8-hour ozone concentration in µg/m³
+pub fn ozone8_ugm3(concentration: f64) -> Option<AirQuality> {
+    ozone8(ugm3_to_ppm(concentration, MOLAR_MASS_O3))
+}
+
+/// Calculates the carbon monoxide Air Quality Index from an 8-hour concentration given in µg/m³
+///
+/// Converts from µg/m³ to ppm (this crate's native unit for [`co`]) using
+/// CO's molar mass, then computes the AQI as normal.
+///
+/// # Arguments
+///
+/// * `concentration` - The 8-hour CO concentration in µg/m³
+pub fn co_ugm3(concentration: f64) -> Option<AirQuality> {
+    co(ugm3_to_ppm(concentration, MOLAR_MASS_CO))
+}
+
+/// Calculates the sulfur dioxide Air Quality Index from a 1-hour concentration given in µg/m³
+///
+/// Converts from µg/m³ to ppb (this crate's native unit for [`so2_1`]) using
+/// SO₂'s molar mass, then computes the AQI as normal.
+///
+/// # Arguments
+///
+/// * `concentration` - The 1-hour SO₂ concentration in µg/m³
+pub fn so2_1_ugm3(concentration: f64) -> Option<AirQuality> {
+    so2_1(ugm3_to_ppb(concentration, MOLAR_MASS_SO2))
+}
+
+/// Calculates the nitrogen dioxide Air Quality Index from a 1-hour concentration given in µg/m³
+///
+/// Converts from µg/m³ to ppb (this crate's native unit for [`no2`]) using
+/// NO₂'s molar mass, then computes the AQI as normal.
+///
+/// # Arguments
+///
+/// * `concentration` - The 1-hour NO₂ concentration in µg/m³
+pub fn no2_ugm3(concentration: f64) -> Option<AirQuality> {
+    no2(ugm3_to_ppb(concentration, MOLAR_MASS_NO2))
+}
+
+/// Identifies which pollutant (and averaging period) a sub-index was
+/// calculated from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Pollutant {
+    /// PM2.5, 24-hour concentration
+    Pm2_5,
+    /// PM10, 24-hour concentration
+    Pm10,
+    /// Ozone, 1-hour concentration
+    Ozone1,
+    /// Ozone, 8-hour concentration
+    Ozone8,
+    /// Carbon monoxide, 8-hour concentration
+    Co,
+    /// Sulfur dioxide, 1-hour concentration
+    So2_1,
+    /// Sulfur dioxide, 24-hour concentration
+    So2_24,
+    /// Nitrogen dioxide, 1-hour concentration
+    No2,
+}
+
+/// A set of simultaneous pollutant readings used to compute an overall AQI.
+///
+/// Any field left as `None` is simply excluded from the calculation, so a
+/// partial sensor set still yields a result.  Units match the corresponding
+/// per-pollutant function in this crate.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PollutantReadings {
+    /// 24-hour PM2.5 concentration, in µg/m³
+    pub pm2_5: Option<f64>,
+    /// 24-hour PM10 concentration, in µg/m³
+    pub pm10: Option<f64>,
+    /// 1-hour ozone concentration, in ppm
+    pub ozone1: Option<f64>,
+    /// 8-hour ozone concentration, in ppm
+    pub ozone8: Option<f64>,
+    /// 8-hour CO concentration, in ppm
+    pub co: Option<f64>,
+    /// 1-hour SO₂ concentration, in ppb
+    pub so2_1: Option<f64>,
+    /// 24-hour SO₂ concentration, in ppb
+    pub so2_24: Option<f64>,
+    /// 1-hour NO₂ concentration, in ppb
+    pub no2: Option<f64>,
+}
+
+/// The result of [`overall_aqi`]: the headline AQI plus which pollutant(s)
+/// it was derived from.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverallAirQuality {
+    /// The overall numerical AQI, the maximum of the individual sub-indices
+    pub aqi: u32,
+    /// The human-friendly interpretation of the overall AQI
+    pub level: AirQualityLevel,
+    /// The pollutant(s) responsible for the overall AQI.  Left empty unless
+    /// the overall AQI exceeds 50, matching the EPA/AirNow convention of
+    /// only naming a primary pollutant once air quality reaches "Moderate".
+    /// More than one entry means a tie.
+    pub primary: std::vec::Vec<Pollutant>,
+}
+
+/// Calculates the overall Air Quality Index from a set of simultaneous
+/// pollutant readings.
+///
+/// This mirrors how EPA/AirNow and similar national indices derive the
+/// headline AQI: each pollutant's sub-index (IAQI) is computed
+/// independently via this crate's per-pollutant functions, and the largest
+/// sub-index becomes the overall AQI.  The pollutant(s) producing that
+/// value are reported as the primary pollutant(s), with ties reported as
+/// multiple primaries.
+///
+/// Readings left as `None`, or whose value is out of range for its
+/// breakpoint table, are simply skipped, so a partial sensor set still
+/// yields a result.  Returns `None` only if every reading was missing or
+/// out of range.
+///
+/// # Arguments
+///
+/// * `readings` - The pollutant concentrations to consider
+#[cfg(feature = "std")]
+pub fn overall_aqi(readings: &PollutantReadings) -> Option<OverallAirQuality> {
+    // Negative concentrations are below every table's range; the
+    // per-pollutant functions would otherwise truncate them to 0.
+    let sub_index = |reading: Option<f64>, f: fn(f64) -> Option<AirQuality>| {
+        reading.filter(|c| *c >= 0.0).and_then(f)
+    };
+    let candidates = [
+        (sub_index(readings.pm2_5, pm2_5), Pollutant::Pm2_5),
+        (sub_index(readings.pm10, pm10), Pollutant::Pm10),
+        (sub_index(readings.ozone1, ozone1), Pollutant::Ozone1),
+        (sub_index(readings.ozone8, ozone8), Pollutant::Ozone8),
+        (sub_index(readings.co, co), Pollutant::Co),
+        (sub_index(readings.so2_1, so2_1), Pollutant::So2_1),
+        (sub_index(readings.so2_24, so2_24), Pollutant::So2_24),
+        (sub_index(readings.no2, no2), Pollutant::No2),
+    ];
+
+    let max_aqi = candidates
+        .iter()
+        .filter_map(|(aq, _)| aq.map(|aq| aq.aqi))
+        .max()?;
+    let level = AirQualityLevel::try_from(max_aqi).ok()?;
+
+    let primary = if max_aqi > 50 {
+        candidates
+            .iter()
+            .filter(|(aq, _)| aq.map(|aq| aq.aqi) == Some(max_aqi))
+            .map(|(_, pollutant)| *pollutant)
+            .collect()
+    } else {
+        std::vec::Vec::new()
+    };
+
+    Some(OverallAirQuality {
+        aqi: max_aqi,
+        level,
+        primary,
+    })
 }
 
 fn round(val: f64) -> u32 {
@@ -672,4 +1096,197 @@ mod tests {
         assert_eq!(round(123.3), 123);
         assert_eq!(round(84.9), 85);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_overall_aqi_dominant_pollutant() {
+        let readings = PollutantReadings {
+            pm2_5: Some(35.4),
+            co: Some(40.4),
+            ..Default::default()
+        };
+        let overall = overall_aqi(&readings).unwrap();
+        assert_eq!(overall.aqi, 400);
+        assert_eq!(overall.level, AirQualityLevel::Hazardous);
+        assert_eq!(overall.primary, vec![Pollutant::Co]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_overall_aqi_ties() {
+        let readings = PollutantReadings {
+            pm2_5: Some(250.5),
+            pm10: Some(425.0),
+            ..Default::default()
+        };
+        let overall = overall_aqi(&readings).unwrap();
+        assert_eq!(overall.primary, vec![Pollutant::Pm2_5, Pollutant::Pm10]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_overall_aqi_no_primary_below_threshold() {
+        let readings = PollutantReadings {
+            pm2_5: Some(12.0),
+            ..Default::default()
+        };
+        let overall = overall_aqi(&readings).unwrap();
+        assert_eq!(overall.aqi, 50);
+        assert!(overall.primary.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_overall_aqi_skips_missing_and_out_of_range() {
+        let readings = PollutantReadings {
+            pm2_5: Some(-1.0),
+            pm10: Some(55.0),
+            ..Default::default()
+        };
+        let overall = overall_aqi(&readings).unwrap();
+        assert_eq!(overall.primary, vec![Pollutant::Pm10]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_overall_aqi_all_missing() {
+        assert_eq!(overall_aqi(&PollutantReadings::default()), None);
+    }
+
+    #[test]
+    fn test_pm2_5_nowcast_steady() {
+        let hourly = [Some(35.0); 12];
+        assert_eq!(pm2_5_nowcast(&hourly).map(|aq| aq.aqi), pm2_5(35.0).map(|aq| aq.aqi));
+    }
+
+    #[test]
+    fn test_pm2_5_nowcast_weights_recent_hours_more() {
+        let mut hourly = [None; 12];
+        hourly[0] = Some(100.0);
+        hourly[1] = Some(10.0);
+        hourly[2] = Some(10.0);
+        let nowcast = nowcast_concentration(&hourly, 0.5).unwrap();
+        let simple_average = (100.0 + 10.0 + 10.0) / 3.0;
+        assert!(nowcast > simple_average && nowcast < 100.0);
+    }
+
+    #[test]
+    fn test_pm2_5_nowcast_requires_two_of_three_recent() {
+        let mut hourly = [None; 12];
+        hourly[0] = Some(35.0);
+        assert_eq!(pm2_5_nowcast(&hourly), None);
+
+        hourly[1] = Some(35.0);
+        assert!(pm2_5_nowcast(&hourly).is_some());
+    }
+
+    #[test]
+    fn test_pm2_5_nowcast_allows_gaps_outside_recent_window() {
+        let mut hourly = [Some(35.0); 12];
+        hourly[5] = None;
+        hourly[8] = None;
+        assert!(pm2_5_nowcast(&hourly).is_some());
+    }
+
+    #[test]
+    fn test_nowcast_concentration_ignores_hours_beyond_the_12_hour_window() {
+        let mut hourly = [Some(35.0); 24];
+        // An outlier outside the 12-hour window must not affect the result.
+        hourly[12] = Some(500.0);
+        let truncated = nowcast_concentration(&hourly[..12], 0.5);
+        let full = nowcast_concentration(&hourly, 0.5);
+        assert_eq!(truncated, full);
+    }
+
+    #[test]
+    fn test_calc_aqi_with_builtin_table_matches_pm2_5() {
+        let result = calc_aqi(&PM25_BREAKPOINTS, 35.4).unwrap();
+        assert_eq!(result.aqi, 100);
+        assert_eq!(result.category, Category::Us(AirQualityLevel::Moderate));
+    }
+
+    #[test]
+    fn test_calc_aqi_with_custom_table() {
+        const FI_PM25_BREAKPOINTS: [Breakpoint; 2] = [
+            Breakpoint {
+                conc_low: 0.0,
+                conc_high: 25.0,
+                aqi_low: 0,
+                aqi_high: 50,
+                category: Category::Named("Satisfactory"),
+            },
+            Breakpoint {
+                conc_low: 25.1,
+                conc_high: 50.0,
+                aqi_low: 51,
+                aqi_high: 100,
+                category: Category::Named("Fair"),
+            },
+        ];
+
+        let result = calc_aqi(&FI_PM25_BREAKPOINTS, 40.0).unwrap();
+        assert_eq!(result.category, Category::Named("Fair"));
+        assert!(result.aqi > 50 && result.aqi <= 100);
+    }
+
+    #[test]
+    fn test_calc_aqi_out_of_range() {
+        assert_eq!(calc_aqi(&PM25_BREAKPOINTS, -1.0), None);
+    }
+
+    #[test]
+    fn test_ppb_ugm3_roundtrip() {
+        let ppb = 100.0;
+        let ugm3 = ppb_to_ugm3(ppb, MOLAR_MASS_NO2);
+        assert!((ugm3_to_ppb(ugm3, MOLAR_MASS_NO2) - ppb).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ppm_ugm3_roundtrip() {
+        let ppm = 0.05;
+        let ugm3 = ppm_to_ugm3(ppm, MOLAR_MASS_O3);
+        assert!((ugm3_to_ppm(ugm3, MOLAR_MASS_O3) - ppm).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no2_ugm3_matches_no2_ppb() {
+        let ppb = 100.0;
+        let ugm3 = ppb_to_ugm3(ppb, MOLAR_MASS_NO2);
+        let expected = no2(ppb).unwrap().aqi;
+        let actual = no2_ugm3(ugm3).unwrap().aqi;
+        assert!(actual.abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn test_co_ugm3_matches_co_ppm() {
+        let ppm = 4.4;
+        let ugm3 = ppm_to_ugm3(ppm, MOLAR_MASS_CO);
+        let expected = co(ppm).unwrap().aqi;
+        let actual = co_ugm3(ugm3).unwrap().aqi;
+        assert!(actual.abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn test_air_quality_level_label() {
+        assert_eq!(AirQualityLevel::Good.label(), "Good");
+        assert_eq!(
+            AirQualityLevel::UnhealthySensitive.label(),
+            "Unhealthy for Sensitive Groups"
+        );
+    }
+
+    #[test]
+    fn test_air_quality_level_color_hex() {
+        assert_eq!(AirQualityLevel::Good.color_hex(), "#00e400");
+        assert_eq!(AirQualityLevel::Hazardous.color_hex(), "#7e0023");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_air_quality_serde_roundtrip() {
+        let aq = pm2_5(35.4).unwrap();
+        let json = serde_json::to_string(&aq).unwrap();
+        let deserialized: AirQuality = serde_json::from_str(&json).unwrap();
+        assert_eq!(aq, deserialized);
+    }
 }